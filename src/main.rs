@@ -1,177 +1,474 @@
+use bit_vec::BitVec;
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use std::collections::HashMap;
+use std::hash::Hash;
+
+// A single arena slot: either a leaf holding a symbol or an internal node
+// pointing at its children by index. Using indices instead of `Box` children
+// keeps the tree cache-friendly and lets it derive `Clone` for cheap reuse.
+#[derive(Clone)]
+struct Node<T> {
+    symbol: Option<T>,
+    left: Option<usize>,
+    right: Option<usize>,
+}
 
-struct HuffmanNode {
-    left: Option<Box<HuffmanNode>>,
-    right: Option<Box<HuffmanNode>>,
-    value: Option<char>,
-    frequency: i32,
+struct HeapEntry {
+    count: i32,
+    index: usize,
 }
 
-impl PartialEq for HuffmanNode {
+impl PartialEq for HeapEntry {
     fn eq(&self, other: &Self) -> bool {
-        self.frequency == other.frequency
+        self.count == other.count
     }
 }
 
-impl Ord for HuffmanNode {
+impl Ord for HeapEntry {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.frequency.cmp(&other.frequency)
+        self.count.cmp(&other.count)
     }
 }
 
-impl Eq for HuffmanNode {}
+impl Eq for HeapEntry {}
 
-impl PartialOrd for HuffmanNode {
+impl PartialOrd for HeapEntry {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.frequency.partial_cmp(&other.frequency)
+        self.count.partial_cmp(&other.count)
     }
 }
 
-struct HuffmanTree {
-    table: HashMap<char, Vec<bool>>,
-    root: Box<HuffmanNode>,
+struct HuffmanTree<T: Clone + Eq + Hash> {
+    table: HashMap<T, Vec<bool>>,
+    nodes: Vec<Node<T>>,
+    root_index: usize,
 }
 
-fn build_table(node: &HuffmanNode, code: Vec<bool>, table: &mut HashMap<char, Vec<bool>>) {
-    match node.value {
-        Some(value) => {
-            display_code(&code);
-            table.insert(value, code);
-            return;
+fn build_table<T: Clone + Eq + Hash>(
+    nodes: &[Node<T>],
+    root_index: usize,
+    table: &mut HashMap<T, Vec<bool>>,
+) {
+    let mut stack = vec![(root_index, Vec::new())];
+
+    while let Some((index, code)) = stack.pop() {
+        let node = &nodes[index];
+
+        match &node.symbol {
+            Some(value) => {
+                display_code(&code);
+                table.insert(value.clone(), code);
+                continue;
+            }
+            None => {}
         }
-        None => {}
-    }
 
-    match &node.left {
-        Some(child) => {
-            let mut child_code = code.clone();
-            child_code.push(false);
-            build_table(&*child, child_code, table);
+        // A canonical length table (e.g. JPEG Annex C) can legitimately leave
+        // some code space unused, so a missing child here just means that
+        // branch has no symbol under it, not a malformed tree.
+        match node.left {
+            Some(left) => {
+                let mut left_code = code.clone();
+                left_code.push(false);
+                stack.push((left, left_code));
+            }
+            None => {}
         }
-        None => panic!("Something strange happening :/"),
-    }
 
-    match &node.right {
-        Some(child) => {
-            let mut child_code = code.clone();
-            child_code.push(true);
-            build_table(&*child, child_code, table);
+        match node.right {
+            Some(right) => {
+                let mut right_code = code.clone();
+                right_code.push(true);
+                stack.push((right, right_code));
+            }
+            None => {}
         }
-        None => panic!("Something strange happening :/"),
     }
 }
 
-fn build_huffman_from_frequencies(frequencies: &HashMap<char, i32>) -> HuffmanTree {
-    let mut unique_chars: Vec<&char> = frequencies.keys().collect();
-    unique_chars.sort_by(|a, b| {
+fn build_huffman_from_frequencies<T: Clone + Eq + Hash>(
+    frequencies: &HashMap<T, i32>,
+) -> HuffmanTree<T> {
+    let mut unique_symbols: Vec<&T> = frequencies.keys().collect();
+    unique_symbols.sort_by(|a, b| {
         frequencies
             .get(*a)
             .unwrap()
             .cmp(&frequencies.get(*b).unwrap())
     });
+
+    let mut nodes: Vec<Node<T>> = Vec::new();
     let mut heap = BinaryHeap::new();
 
-    for char in &unique_chars {
-        let node = HuffmanNode {
+    for symbol in &unique_symbols {
+        let count = *frequencies.get(*symbol).unwrap();
+        let index = nodes.len();
+        nodes.push(Node {
+            symbol: Some((*symbol).clone()),
             left: None,
             right: None,
-            value: Some(**char),
-            frequency: *frequencies.get(*char).unwrap(),
-        };
-        heap.push(Reverse(node));
+        });
+        heap.push(Reverse(HeapEntry { count, index }));
     }
 
     while heap.len() > 1 {
         if let Some(Reverse(left)) = heap.pop() {
             if let Some(Reverse(right)) = heap.pop() {
-                let new_frequency = left.frequency + right.frequency;
-                let new_node = HuffmanNode {
-                    left: Some(Box::new(left)),
-                    right: Some(Box::new(right)),
-                    frequency: new_frequency,
-                    value: None,
-                };
-                heap.push(Reverse(new_node));
+                let new_count = left.count + right.count;
+                let new_index = nodes.len();
+                nodes.push(Node {
+                    symbol: None,
+                    left: Some(left.index),
+                    right: Some(right.index),
+                });
+                heap.push(Reverse(HeapEntry { count: new_count, index: new_index }));
             };
         };
     }
 
     if let Some(Reverse(root)) = heap.pop() {
         let mut table = HashMap::new();
-        build_table(&root, Vec::new(), &mut table);
+        build_table(&nodes, root.index, &mut table);
 
-        return HuffmanTree { table: table, root: Box::new(root) };
+        return HuffmanTree { table: table, nodes: nodes, root_index: root.index };
     } else {
         panic!("Something strange going on :/")
     }
 }
 
-impl HuffmanTree {
-    fn new(chars: &[char]) -> HuffmanTree {
-        let frequencies = get_frequencies(&chars);
+impl<T: Clone + Eq + Hash> HuffmanTree<T> {
+    fn new(symbols: &[T]) -> HuffmanTree<T> {
+        let frequencies = get_frequencies(&symbols);
         return build_huffman_from_frequencies(&frequencies);
     }
 
-    fn get_code(&self, char: char) -> Option<&Vec<bool>> {
-        self.table.get(&char)
+    fn get_code(&self, symbol: &T) -> Option<&Vec<bool>> {
+        self.table.get(symbol)
     }
 
-    fn encode(&self, chars: &[char]) -> Vec<bool> {
+    fn encode(&self, symbols: &[T]) -> Vec<bool> {
         let mut result = Vec::new();
-        for c in chars {
-            let code_option = self.get_code(*c);
+        for s in symbols {
+            let code_option = self.get_code(s);
             match code_option {
                 Some(code) => {
                     result.extend(code);
                 }
                 None => {
-                    panic!("'{c}' was not found in huffman tree :: Failed to encode")
+                    panic!("symbol was not found in huffman tree :: Failed to encode")
                 }
             }
         }
         return result
     }
 
-    fn decode(&self, code: &Vec<bool>) -> Vec<char> {
-        let mut result = Vec::new();
+    fn decode(&self, code: &Vec<bool>) -> Vec<T> {
+        let decode_table = self.compile_decode_table();
+        decode_table.decode(code)
+    }
+
+    // Builds a lookup table that reads `DECODE_WINDOW_BITS` at a time instead of
+    // walking the tree bit by bit, so decoding amortizes over whole bytes.
+    fn compile_decode_table(&self) -> DecodeTable<T> {
+        build_decode_table(&self.nodes, self.root_index, DECODE_WINDOW_BITS)
+    }
+}
+
+// A canonical Huffman code: `bits` of `value`, read most-significant bit first.
+struct Code {
+    value: u64,
+    bits: u32,
+}
 
-        let mut current_node = &*self.root;
-        for bit in code {
-            println!("{bit}");
-            match &bit {
-                true => {
-                    if let Some(new_current_node) = &current_node.right {
-                        current_node = new_current_node;
+impl<T: Clone + Eq + Hash + Ord> HuffmanTree<T> {
+    // Only the code *length* of each symbol needs to be serialized: canonical
+    // codes are fully determined by the per-symbol lengths, so the decoder can
+    // reconstruct identical codes from those lengths alone.
+    fn to_code_lengths(&self) -> Vec<(T, u8)> {
+        let mut lengths: Vec<(T, u8)> = self
+            .table
+            .iter()
+            .map(|(symbol, code)| (symbol.clone(), code.len() as u8))
+            .collect();
+        lengths.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+        return lengths;
+    }
+
+    fn from_code_lengths(lengths: &[(T, u8)]) -> HuffmanTree<T> {
+        let codes = canonical_codes_from_lengths(lengths);
+        return build_tree_from_codes(&codes);
+    }
+}
+
+// Sorts symbols by `(length, symbol)` ascending, then assigns the first
+// symbol code `0` and each subsequent symbol `(prev_code + 1) << (length
+// increase)`, which is the standard canonical Huffman code assignment.
+fn canonical_codes_from_lengths<T: Clone + Eq + Hash + Ord>(lengths: &[(T, u8)]) -> Vec<(T, Code)> {
+    let mut sorted = lengths.to_vec();
+    sorted.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+
+    let mut codes = Vec::new();
+    let mut prev_code: u64 = 0;
+    let mut prev_length: u8 = 0;
+
+    for (index, (symbol, length)) in sorted.iter().enumerate() {
+        let code_value = if index == 0 {
+            0
+        } else {
+            (prev_code + 1) << (length - prev_length)
+        };
+        codes.push((symbol.clone(), Code { value: code_value, bits: *length as u32 }));
+        prev_code = code_value;
+        prev_length = *length;
+    }
+
+    return codes;
+}
+
+// Rebuilds the arena by walking each canonical code bit by bit, creating
+// internal nodes on demand, then derives `table` from the resulting tree.
+fn build_tree_from_codes<T: Clone + Eq + Hash>(codes: &[(T, Code)]) -> HuffmanTree<T> {
+    let root_index = 0;
+    let mut nodes: Vec<Node<T>> = vec![Node {
+        symbol: None,
+        left: None,
+        right: None,
+    }];
+
+    for (symbol, code) in codes {
+        let mut current = root_index;
+        for bit_index in 0..code.bits {
+            let shift = code.bits - 1 - bit_index;
+            let bit = (code.value >> shift) & 1 == 1;
+            let next = if bit { nodes[current].right } else { nodes[current].left };
+            let next_index = match next {
+                Some(existing) => existing,
+                None => {
+                    let new_index = nodes.len();
+                    nodes.push(Node {
+                        symbol: None,
+                        left: None,
+                        right: None,
+                    });
+                    if bit {
+                        nodes[current].right = Some(new_index);
+                    } else {
+                        nodes[current].left = Some(new_index);
                     }
+                    new_index
                 }
-                false => {
-                    if let Some(new_current_node) = &current_node.left {
-                        current_node = new_current_node;
+            };
+            current = next_index;
+        }
+        nodes[current].symbol = Some(symbol.clone());
+    }
+
+    let mut table = HashMap::new();
+    build_table(&nodes, root_index, &mut table);
+
+    return HuffmanTree { table: table, nodes: nodes, root_index: root_index };
+}
+
+const DECODE_WINDOW_BITS: usize = 8;
+
+enum DecodeEntry<T> {
+    // A complete code was found within the window; `used_bits` is how many of
+    // the window's bits actually belong to the code, the rest are unread.
+    Done(T, u8),
+    Continue(Box<DecodeTable<T>>),
+}
+
+struct DecodeTable<T> {
+    entries: Vec<Option<DecodeEntry<T>>>,
+}
+
+impl<T: Clone> DecodeTable<T> {
+    fn decode(&self, code: &Vec<bool>) -> Vec<T> {
+        let mut result = Vec::new();
+        let mut position = 0;
+
+        while position < code.len() {
+            let mut table = self;
+            loop {
+                let window = read_window(code, position, DECODE_WINDOW_BITS);
+                let index = bits_to_index(&window);
+                match table.entries[index].as_ref() {
+                    Some(DecodeEntry::Done(symbol, used_bits)) => {
+                        result.push(symbol.clone());
+                        position += *used_bits as usize;
+                        break;
                     }
+                    Some(DecodeEntry::Continue(sub_table)) => {
+                        position += DECODE_WINDOW_BITS;
+                        table = &**sub_table;
+                    }
+                    None => panic!("invalid code :: Failed to decode"),
                 }
             }
-            match current_node.value {
-                Some(value) => {
-                    result.push(value);
-                    current_node = &*self.root;
-                }
-                None => {
+        }
 
+        return result;
+    }
+}
+
+fn read_window(code: &Vec<bool>, position: usize, bits: usize) -> Vec<bool> {
+    let mut window = Vec::with_capacity(bits);
+    for offset in 0..bits {
+        window.push(*code.get(position + offset).unwrap_or(&false));
+    }
+    return window;
+}
+
+fn bits_to_index(bits: &[bool]) -> usize {
+    let mut index = 0usize;
+    for bit in bits {
+        index = (index << 1) | (*bit as usize);
+    }
+    return index;
+}
+
+fn index_to_bits(index: usize, bits: usize) -> Vec<bool> {
+    let mut result = Vec::with_capacity(bits);
+    for offset in (0..bits).rev() {
+        result.push((index >> offset) & 1 == 1);
+    }
+    return result;
+}
+
+// For every possible `window_bits`-wide prefix, follow it from `node_index` and
+// record either the symbol it lands on (plus how many bits it actually took)
+// or, if the window runs out before reaching a leaf, a continuation table
+// rooted at wherever that prefix left off.
+fn build_decode_table<T: Clone>(
+    nodes: &[Node<T>],
+    node_index: usize,
+    window_bits: usize,
+) -> DecodeTable<T> {
+    let size = 1usize << window_bits;
+    let mut entries: Vec<Option<DecodeEntry<T>>> = (0..size).map(|_| None).collect();
+
+    for index in 0..size {
+        let bits = index_to_bits(index, window_bits);
+        let mut current = node_index;
+        let mut used_bits = 0;
+        let mut symbol = nodes[current].symbol.as_ref();
+        let mut reachable = true;
+
+        if symbol.is_none() {
+            for bit in &bits {
+                let next = if *bit { nodes[current].right } else { nodes[current].left };
+                match next {
+                    Some(child) => current = child,
+                    None => {
+                        reachable = false;
+                        break;
+                    }
+                }
+                used_bits += 1;
+                if nodes[current].symbol.is_some() {
+                    symbol = nodes[current].symbol.as_ref();
+                    break;
                 }
             }
         }
 
+        // A prefix that walks off the tree lands in code space a canonical
+        // length table left unused; no real encoded stream produces it, so
+        // leave the entry empty instead of recursing on `current` again
+        // (which made no progress and would recurse forever).
+        entries[index] = if !reachable {
+            None
+        } else {
+            match symbol {
+                Some(value) => Some(DecodeEntry::Done(value.clone(), used_bits as u8)),
+                None => Some(DecodeEntry::Continue(Box::new(build_decode_table(
+                    nodes,
+                    current,
+                    window_bits,
+                )))),
+            }
+        };
+    }
+
+    return DecodeTable { entries };
+}
+
+// Scoped to `u8` rather than generic `T`: the header below serializes each
+// symbol as a single byte, which only makes sense for a byte alphabet.
+// Arbitrary `T` would need a serialization trait bound to support this.
+impl HuffmanTree<u8> {
+    // Header layout: symbol_count:u32 | table_len:u32 | (symbol:u8, frequency:u32) * table_len | packed bits
+    fn compress(&self, symbols: &[u8]) -> Vec<u8> {
+        let frequencies = get_frequencies(symbols);
+        let mut result = Vec::new();
+        result.extend((symbols.len() as u32).to_le_bytes());
+        result.extend((frequencies.len() as u32).to_le_bytes());
+        for (symbol, frequency) in &frequencies {
+            result.push(*symbol);
+            result.extend((*frequency as u32).to_le_bytes());
+        }
+
+        let code = self.encode(symbols);
+        let mut bits = BitVec::from_elem(code.len(), false);
+        for (index, bit) in code.iter().enumerate() {
+            bits.set(index, *bit);
+        }
+        result.extend(bits.to_bytes());
+
         return result;
     }
+
+    fn decompress(data: &[u8]) -> Vec<u8> {
+        let symbol_count = u32::from_le_bytes(data[0..4].try_into().unwrap()) as usize;
+        let table_len = u32::from_le_bytes(data[4..8].try_into().unwrap()) as usize;
+
+        let mut offset = 8;
+        let mut frequencies = HashMap::new();
+        for _ in 0..table_len {
+            let symbol = data[offset];
+            let frequency = u32::from_le_bytes(data[offset + 1..offset + 5].try_into().unwrap());
+            frequencies.insert(symbol, frequency as i32);
+            offset += 5;
+        }
+
+        // A single-symbol alphabet collapses to a root leaf with a 0-bit code,
+        // so there's no bitstream to decode at all: every symbol is the same one.
+        if table_len == 1 {
+            let symbol = *frequencies.keys().next().unwrap();
+            return vec![symbol; symbol_count];
+        }
+
+        let huffman_tree = build_huffman_from_frequencies(&frequencies);
+        let bits = BitVec::from_bytes(&data[offset..]);
+        let code: Vec<bool> = bits.iter().collect();
+
+        // trailing padding bits in the final byte aren't real codes, so only
+        // keep the symbols we know were actually encoded.
+        let mut decoded = huffman_tree.decode(&code);
+        decoded.truncate(symbol_count);
+        return decoded;
+    }
+
+    // Builds a tree straight from a JPEG/DEFLATE-style length table: `lengths[i]`
+    // is the code length of symbol `i`, with `0` meaning the symbol is unused.
+    fn from_lengths(lengths: &[u8]) -> HuffmanTree<u8> {
+        let code_lengths: Vec<(u8, u8)> = lengths
+            .iter()
+            .enumerate()
+            .filter(|(_, &length)| length > 0)
+            .map(|(symbol, &length)| (symbol as u8, length))
+            .collect();
+
+        return HuffmanTree::from_code_lengths(&code_lengths);
+    }
 }
 
-fn get_frequencies(chars: &[char]) -> HashMap<char, i32> {
+fn get_frequencies<T: Clone + Eq + Hash>(symbols: &[T]) -> HashMap<T, i32> {
     let mut frequencies = HashMap::new();
 
-    for c in chars {
-        frequencies.entry(*c).and_modify(|x| *x += 1).or_insert(1);
+    for s in symbols {
+        frequencies.entry(s.clone()).and_modify(|x| *x += 1).or_insert(1);
     }
 
     return frequencies;
@@ -204,12 +501,12 @@ mod tests {
         ]);
         let huffman_tree = build_huffman_from_frequencies(&frequencies);
 
-        let f_code = huffman_tree.get_code('f').unwrap();
-        let e_code = huffman_tree.get_code('e').unwrap();
-        let c_code = huffman_tree.get_code('c').unwrap();
-        let b_code = huffman_tree.get_code('b').unwrap();
-        let d_code = huffman_tree.get_code('d').unwrap();
-        let a_code = huffman_tree.get_code('a').unwrap();
+        let f_code = huffman_tree.get_code(&'f').unwrap();
+        let e_code = huffman_tree.get_code(&'e').unwrap();
+        let c_code = huffman_tree.get_code(&'c').unwrap();
+        let b_code = huffman_tree.get_code(&'b').unwrap();
+        let d_code = huffman_tree.get_code(&'d').unwrap();
+        let a_code = huffman_tree.get_code(&'a').unwrap();
 
         assert_eq!(f_code, &[true, true, false, false]);
         assert_eq!(e_code, &[true, true, false, true]);
@@ -218,6 +515,93 @@ mod tests {
         assert_eq!(d_code, &[true, true, true]);
         assert_eq!(a_code, &[false]);
     }
+
+    #[test]
+    fn test_compress_decompress_round_trip() {
+        let bytes = "aaaabbbccd".as_bytes();
+        let huffman_tree = HuffmanTree::new(bytes);
+        let compressed = huffman_tree.compress(bytes);
+        let decompressed = HuffmanTree::decompress(&compressed);
+
+        assert_eq!(decompressed, bytes);
+    }
+
+    #[test]
+    fn test_compress_decompress_single_symbol() {
+        let bytes = [1u8, 1, 1, 1, 1];
+        let huffman_tree = HuffmanTree::new(&bytes);
+        let compressed = huffman_tree.compress(&bytes);
+        let decompressed = HuffmanTree::decompress(&compressed);
+
+        assert_eq!(decompressed, bytes);
+    }
+
+    #[test]
+    fn test_decode_table_spans_multiple_windows() {
+        // Fibonacci-shaped frequencies push the deepest code past
+        // DECODE_WINDOW_BITS, so decoding this exercises DecodeEntry::Continue
+        // chaining across more than one compiled window.
+        let frequencies = HashMap::from([
+            (0u8, 1), (1u8, 1), (2u8, 2), (3u8, 3), (4u8, 5), (5u8, 8),
+            (6u8, 13), (7u8, 21), (8u8, 34), (9u8, 55), (10u8, 89), (11u8, 144),
+        ]);
+        let huffman_tree = build_huffman_from_frequencies(&frequencies);
+        let deepest_code = huffman_tree.get_code(&0u8).unwrap();
+        assert!(deepest_code.len() > DECODE_WINDOW_BITS);
+
+        let symbols = [0u8, 1, 11, 5, 0];
+        let encoded = huffman_tree.encode(&symbols);
+        let decoded = huffman_tree.decode(&encoded);
+
+        assert_eq!(decoded, symbols);
+    }
+
+    #[test]
+    fn test_code_lengths_round_trip() {
+        let chars = ['a', 'a', 'b', 'c', 'd', 'd', 'd', 'e', 'e', 'd'];
+        let huffman_tree = HuffmanTree::new(&chars);
+
+        let code_lengths = huffman_tree.to_code_lengths();
+        let canonical_tree = HuffmanTree::from_code_lengths(&code_lengths);
+        let canonical_encoded = canonical_tree.encode(&chars);
+        let canonical_decoded = canonical_tree.decode(&canonical_encoded);
+
+        assert_eq!(canonical_decoded, chars);
+        assert_eq!(canonical_tree.to_code_lengths(), code_lengths);
+    }
+
+    #[test]
+    fn test_from_lengths_round_trip() {
+        let bytes = "aaaabbbccd".as_bytes();
+        let byte_tree = HuffmanTree::new(bytes);
+
+        let mut byte_lengths = [0u8; 256];
+        for (symbol, length) in byte_tree.to_code_lengths() {
+            byte_lengths[symbol as usize] = length;
+        }
+
+        let jpeg_style_tree = HuffmanTree::from_lengths(&byte_lengths);
+        let jpeg_style_encoded = jpeg_style_tree.encode(bytes);
+        let jpeg_style_decoded = jpeg_style_tree.decode(&jpeg_style_encoded);
+
+        assert_eq!(jpeg_style_decoded, bytes);
+    }
+
+    #[test]
+    fn test_from_lengths_incomplete_table() {
+        // A length table with a single non-zero entry leaves most of the code
+        // space unused, same as a real JPEG/DEFLATE table can (Annex C). The
+        // resulting tree is one-sided rather than a complete binary tree.
+        let mut lengths = [0u8; 256];
+        lengths[0] = 3;
+
+        let tree = HuffmanTree::from_lengths(&lengths);
+        let symbols = [0u8, 0, 0];
+        let encoded = tree.encode(&symbols);
+        let decoded = tree.decode(&encoded);
+
+        assert_eq!(decoded, symbols);
+    }
 }
 
 fn char_vec_to_string(chars: &Vec<char>) -> String {
@@ -240,4 +624,33 @@ fn main() {
     println!("Input: \t\t{}", char_slice_to_string(&chars));
     println!("Encoded: \t{}", code_to_string(&encoded));
     println!("Decoded: \t{}", char_vec_to_string(&decoded));
+
+    let bytes = "aaaabbbccd".as_bytes();
+    let byte_tree = HuffmanTree::new(bytes);
+    let compressed = byte_tree.compress(bytes);
+    let decompressed = HuffmanTree::decompress(&compressed);
+
+    println!("Compressed bytes: \t{}", compressed.len());
+    println!("Decompressed: \t\t{}", String::from_utf8_lossy(&decompressed));
+
+    let code_lengths = huffman_tree.to_code_lengths();
+    let canonical_tree = HuffmanTree::from_code_lengths(&code_lengths);
+    let canonical_encoded = canonical_tree.encode(&chars);
+    let canonical_decoded = canonical_tree.decode(&canonical_encoded);
+
+    println!("Code lengths: \t{:?}", code_lengths);
+    println!("Canonical decoded: {}", char_vec_to_string(&canonical_decoded));
+
+    let mut byte_lengths = [0u8; 256];
+    for (symbol, length) in byte_tree.to_code_lengths() {
+        byte_lengths[symbol as usize] = length;
+    }
+    let jpeg_style_tree = HuffmanTree::from_lengths(&byte_lengths);
+    let jpeg_style_encoded = jpeg_style_tree.encode(bytes);
+    let jpeg_style_decoded = jpeg_style_tree.decode(&jpeg_style_encoded);
+
+    println!(
+        "From lengths decoded: {}",
+        String::from_utf8_lossy(&jpeg_style_decoded)
+    );
 }